@@ -2,10 +2,10 @@ use anyhow::Result;
 use nova_plugin_api::{
     EventBus, PluginConfig, PluginContext, PluginRegistry, PluginCapabilities,
 };
-use nova_ui::NovaApp;
+use nova_ui::{Locale, NovaApp};
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::info;
+use tracing::{info, warn};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -17,6 +17,9 @@ async fn main() -> Result<()> {
     // Initialize plugin system
     let plugin_system = PluginSystem::new().await?;
     let registry_clone = plugin_system.registry.clone();
+
+    // Resolve display language, `--lang <it|en>` overrides the environment
+    let locale = lang_override().unwrap_or_else(Locale::detect);
     
     // Run UI
     let options = eframe::NativeOptions {
@@ -30,7 +33,7 @@ async fn main() -> Result<()> {
         "NovaPcSuite",
         options,
         Box::new(move |_cc| {
-            Box::new(NovaApp::new(registry_clone))
+            Box::new(NovaApp::with_locale(registry_clone, locale))
         }),
     )
     .map_err(|e| anyhow::anyhow!("Failed to run UI: {}", e))?;
@@ -41,6 +44,24 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Parse the `--lang <tag>` / `--lang=<tag>` command line override
+fn lang_override() -> Option<Locale> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let tag = args.iter().enumerate().find_map(|(i, arg)| {
+        if arg == "--lang" {
+            args.get(i + 1).cloned()
+        } else {
+            arg.strip_prefix("--lang=").map(str::to_string)
+        }
+    })?;
+
+    let locale = Locale::from_tag(&tag);
+    if locale.is_none() {
+        warn!("Unsupported language '{}', falling back to environment", tag);
+    }
+    locale
+}
+
 /// Core plugin system management
 pub struct PluginSystem {
     pub registry: Arc<PluginRegistry>,
@@ -0,0 +1,68 @@
+use thiserror::Error;
+
+/// Result type for NovaPcSuite library operations
+pub type NovaResult<T> = std::result::Result<T, NovaError>;
+
+/// Structured error shared by all NovaPcSuite library crates
+///
+/// Library APIs return this instead of `anyhow::Error` so that callers can
+/// match on the kind of failure rather than on message strings.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum NovaError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("TOML error: {0}")]
+    Toml(#[from] toml::de::Error),
+
+    #[error("Plugin {plugin_id} requires API version {required}, but current version is {current}")]
+    IncompatibleApiVersion {
+        plugin_id: String,
+        required: u32,
+        current: u32,
+    },
+
+    #[error("Invalid plugin descriptor: {0}")]
+    InvalidDescriptor(String),
+
+    #[error("Plugin with ID '{0}' is already registered")]
+    PluginAlreadyRegistered(String),
+
+    #[error("Plugin '{0}' not found")]
+    PluginNotFound(String),
+
+    #[error("Plugin '{plugin_id}' failed: {message}")]
+    Plugin { plugin_id: String, message: String },
+}
+
+impl NovaError {
+    /// Create an error reported by a plugin
+    pub fn plugin(plugin_id: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::Plugin {
+            plugin_id: plugin_id.into(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_io_error_conversion() {
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "missing");
+        let error: NovaError = io_error.into();
+        assert!(matches!(error, NovaError::Io(_)));
+    }
+
+    #[test]
+    fn test_plugin_error_message() {
+        let error = NovaError::plugin("example-plugin", "not initialized");
+        assert_eq!(error.to_string(), "Plugin 'example-plugin' failed: not initialized");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::NovaResult;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -40,7 +41,7 @@ impl PluginConfig {
     }
 
     /// Load configuration from disk
-    pub async fn load(&mut self) -> anyhow::Result<()> {
+    pub async fn load(&mut self) -> NovaResult<()> {
         let config_file = self.config_dir.join("plugins.json");
         
         if !config_file.exists() {
@@ -58,7 +59,7 @@ impl PluginConfig {
     }
 
     /// Save configuration to disk
-    pub async fn save(&self) -> anyhow::Result<()> {
+    pub async fn save(&self) -> NovaResult<()> {
         tokio::fs::create_dir_all(&self.config_dir).await?;
         
         let config_file = self.config_dir.join("plugins.json");
@@ -1,3 +1,4 @@
+use crate::{NovaError, NovaResult};
 use serde::{Deserialize, Serialize};
 use semver::Version;
 use std::collections::HashMap;
@@ -19,22 +20,21 @@ pub struct PluginDescriptor {
 
 impl PluginDescriptor {
     /// Validate that this plugin descriptor is compatible with the current API
-    pub fn validate_compatibility(&self) -> anyhow::Result<()> {
+    pub fn validate_compatibility(&self) -> NovaResult<()> {
         if self.api_version != super::CURRENT_API_VERSION {
-            anyhow::bail!(
-                "Plugin {} requires API version {}, but current version is {}",
-                self.id,
-                self.api_version,
-                super::CURRENT_API_VERSION
-            );
+            return Err(NovaError::IncompatibleApiVersion {
+                plugin_id: self.id.clone(),
+                required: self.api_version,
+                current: super::CURRENT_API_VERSION,
+            });
         }
         
         if self.id.is_empty() {
-            anyhow::bail!("Plugin ID cannot be empty");
+            return Err(NovaError::InvalidDescriptor("Plugin ID cannot be empty".to_string()));
         }
         
         if self.name.is_empty() {
-            anyhow::bail!("Plugin name cannot be empty");
+            return Err(NovaError::InvalidDescriptor("Plugin name cannot be empty".to_string()));
         }
         
         Ok(())
@@ -59,7 +59,7 @@ pub enum PluginCategory {
 }
 
 /// Parse plugin descriptor from TOML content
-pub fn parse_plugin_descriptor(toml_content: &str) -> anyhow::Result<PluginDescriptor> {
+pub fn parse_plugin_descriptor(toml_content: &str) -> NovaResult<PluginDescriptor> {
     let descriptor: PluginDescriptor = toml::from_str(toml_content)?;
     descriptor.validate_compatibility()?;
     Ok(descriptor)
@@ -68,7 +68,6 @@ pub fn parse_plugin_descriptor(toml_content: &str) -> anyhow::Result<PluginDescr
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::PluginCapabilities;
 
     #[test]
     fn test_parse_valid_descriptor() {
@@ -76,7 +75,7 @@ mod tests {
 id = "example-plugin"
 name = "Example Plugin"
 version = "1.0.0"
-api_version = 1
+api_version = 2
 authors = ["Test Author"]
 description = "A test plugin"
 categories = ["backup", "ui"]
@@ -96,7 +95,7 @@ some-dep = "1.0"
         let descriptor = parse_plugin_descriptor(toml_content).unwrap();
         assert_eq!(descriptor.id, "example-plugin");
         assert_eq!(descriptor.name, "Example Plugin");
-        assert_eq!(descriptor.api_version, 1);
+        assert_eq!(descriptor.api_version, 2);
         assert!(descriptor.capabilities.file_system_access);
         assert!(!descriptor.capabilities.network_access);
     }
@@ -124,9 +123,9 @@ config_ui = false
 "#;
 
         let result = parse_plugin_descriptor(toml_content);
-        assert!(result.is_err());
-        let error_msg = result.unwrap_err().to_string();
-        // The error should be about API version compatibility
-        assert!(error_msg.contains("requires API version 999") || error_msg.contains("current version is 1"));
+        assert!(matches!(
+            result,
+            Err(NovaError::IncompatibleApiVersion { required: 999, .. })
+        ));
     }
 }
\ No newline at end of file
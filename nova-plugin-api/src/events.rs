@@ -1,3 +1,4 @@
+use crate::NovaResult;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -11,6 +12,12 @@ pub struct EventBus {
     subscribers: Arc<RwLock<HashMap<String, PluginEventSubscription>>>,
 }
 
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EventBus {
     pub fn new() -> Self {
         let (sender, _) = broadcast::channel(1000);
@@ -21,15 +28,19 @@ impl EventBus {
     }
 
     /// Publish an event to all subscribers
-    pub async fn publish(&self, event: NovaEvent) -> anyhow::Result<()> {
+    pub async fn publish(&self, event: NovaEvent) -> NovaResult<()> {
+        self.publish_now(event);
+        Ok(())
+    }
+
+    /// Publish an event from synchronous code, such as plugin callbacks
+    pub fn publish_now(&self, event: NovaEvent) {
         match self.sender.send(event) {
             Ok(subscriber_count) => {
                 tracing::debug!("Published event to {} subscribers", subscriber_count);
-                Ok(())
             }
             Err(_) => {
-                tracing::warn!("No subscribers for event");
-                Ok(())
+                tracing::debug!("No subscribers for event");
             }
         }
     }
@@ -112,6 +123,10 @@ pub enum EventType {
     PluginLoaded,
     PluginUnloaded,
     ConfigChanged,
+    ExportProgress,
+    ExportCompleted,
+    ExportFailed,
+    SourceProgress,
 }
 
 /// Events that can be published in the system
@@ -156,6 +171,52 @@ impl NovaEvent {
         )
     }
 
+    /// Create an export progress event
+    pub fn export_progress(exporter_id: String, progress: &crate::ExportProgress) -> Self {
+        Self::new(
+            EventType::ExportProgress,
+            exporter_id,
+            serde_json::json!(progress),
+        )
+    }
+
+    /// Create an export completed event
+    pub fn export_completed(
+        exporter_id: String,
+        snapshot_id: String,
+        location: &crate::RemoteLocation,
+    ) -> Self {
+        Self::new(
+            EventType::ExportCompleted,
+            exporter_id,
+            serde_json::json!({
+                "snapshot_id": snapshot_id,
+                "uri": location.uri
+            }),
+        )
+    }
+
+    /// Create an export failed event
+    pub fn export_failed(exporter_id: String, snapshot_id: String, error: String) -> Self {
+        Self::new(
+            EventType::ExportFailed,
+            exporter_id,
+            serde_json::json!({
+                "snapshot_id": snapshot_id,
+                "error": error
+            }),
+        )
+    }
+
+    /// Create a source progress event
+    pub fn source_progress(source_id: String, progress: &crate::SourceProgress) -> Self {
+        Self::new(
+            EventType::SourceProgress,
+            source_id,
+            serde_json::json!(progress),
+        )
+    }
+
     /// Create a plugin loaded event
     pub fn plugin_loaded(plugin_id: String) -> Self {
         Self::new(
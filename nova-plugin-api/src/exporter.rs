@@ -0,0 +1,76 @@
+use crate::PluginResult;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Finalized backup manifest handed to exporter plugins
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportManifest {
+    pub snapshot_id: String,
+    pub label: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub files: Vec<ExportFileEntry>,
+    /// Locations recorded by exporters that already stored this snapshot
+    pub remote_locations: Vec<RemoteLocation>,
+}
+
+impl ExportManifest {
+    /// Total number of bytes referenced by the manifest
+    pub fn total_bytes(&self) -> u64 {
+        self.files.iter().map(|f| f.size).sum()
+    }
+
+    /// Total number of chunk references in the manifest
+    pub fn total_chunks(&self) -> usize {
+        self.files.iter().map(|f| f.chunks.len()).sum()
+    }
+}
+
+/// File entry within an export manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportFileEntry {
+    pub path: String,
+    pub size: u64,
+    /// Ordered chunk IDs making up the file content
+    pub chunks: Vec<String>,
+}
+
+/// Where an exporter stored a snapshot, recorded back into the manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteLocation {
+    pub exporter_id: String,
+    pub uri: String,
+    pub exported_at: chrono::DateTime<chrono::Utc>,
+    pub metadata: HashMap<String, String>,
+}
+
+/// Upload progress reported by an exporter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportProgress {
+    pub exporter_id: String,
+    pub snapshot_id: String,
+    pub bytes_uploaded: u64,
+    pub bytes_total: u64,
+    pub chunks_uploaded: usize,
+    pub chunks_total: usize,
+}
+
+/// Host-provided read access to stored chunk data
+pub trait ChunkReader: Send + Sync {
+    /// Read the content of a chunk by ID
+    fn read_chunk(&self, chunk_id: &str) -> PluginResult<Vec<u8>>;
+}
+
+/// Contract for `Exporter` and `CloudSync` plugins
+///
+/// Exporters are invoked by the host once a backup is finalized. They read
+/// chunk data through the provided [`ChunkReader`], report progress through
+/// the callback and return the remote location the snapshot was stored at.
+pub trait ExporterPlugin: Send + Sync {
+    /// Export a finalized snapshot to the exporter's destination
+    fn export(
+        &mut self,
+        manifest: &ExportManifest,
+        chunks: &dyn ChunkReader,
+        progress: &mut dyn FnMut(ExportProgress),
+    ) -> PluginResult<RemoteLocation>;
+}
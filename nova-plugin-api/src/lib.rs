@@ -3,24 +3,30 @@ pub mod registry;
 pub mod events;
 pub mod config;
 pub mod sandbox;
+pub mod exporter;
+pub mod source;
 
 pub use descriptor::*;
 pub use registry::*;
 pub use events::*;
 pub use config::*;
 pub use sandbox::*;
+pub use exporter::*;
+pub use source::*;
+pub use nova_error::{NovaError, NovaResult};
 
-use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::any::Any;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 /// Current plugin API version
-pub const CURRENT_API_VERSION: u32 = 1;
+///
+/// Version 2 changed `PluginResult` from `anyhow::Result` to `NovaResult`.
+pub const CURRENT_API_VERSION: u32 = 2;
 
 /// Result type for plugin operations
-pub type PluginResult<T> = Result<T>;
+pub type PluginResult<T> = NovaResult<T>;
 
 /// Plugin context provided during initialization and runtime
 #[derive(Debug, Clone)]
@@ -31,7 +37,7 @@ pub struct PluginContext {
 }
 
 /// Capabilities that a plugin can request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PluginCapabilities {
     pub file_system_access: bool,
     pub network_access: bool,
@@ -41,19 +47,6 @@ pub struct PluginCapabilities {
     pub config_ui: bool,
 }
 
-impl Default for PluginCapabilities {
-    fn default() -> Self {
-        Self {
-            file_system_access: false,
-            network_access: false,
-            system_info_access: false,
-            backup_events: false,
-            ui_panels: false,
-            config_ui: false,
-        }
-    }
-}
-
 /// Core trait that all plugins must implement
 pub trait NovaPlugin: Send + Sync {
     /// Get plugin descriptor metadata
@@ -68,6 +61,16 @@ pub trait NovaPlugin: Send + Sync {
     /// Check if plugin is healthy/operational
     fn health_check(&self) -> PluginResult<PluginHealth>;
     
+    /// Get the exporter contract if this plugin exports finalized backups
+    fn as_exporter(&mut self) -> Option<&mut dyn ExporterPlugin> {
+        None
+    }
+    
+    /// Get the source contract if this plugin provides a backup source
+    fn as_source(&mut self) -> Option<&mut dyn SourcePlugin> {
+        None
+    }
+    
     /// Get plugin as Any for downcasting to specific plugin types
     fn as_any(&self) -> &dyn Any;
     fn as_any_mut(&mut self) -> &mut dyn Any;
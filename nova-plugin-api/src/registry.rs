@@ -1,12 +1,28 @@
-use crate::{NovaPlugin, PluginDescriptor, PluginResult, PluginContext, PluginHealth};
-use anyhow::anyhow;
+use crate::{
+    ChunkReader, ExportManifest, NovaError, NovaEvent, NovaPlugin, PluginContext, PluginDescriptor,
+    PluginHealth, PluginResult, RemoteLocation, SourceSummary, SourceWriter,
+};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
+
+/// Plugin instance shared between the registry and running plugin tasks
+type SharedPlugin = Arc<Mutex<Box<dyn NovaPlugin>>>;
+
+/// Registered plugin with its metadata cached outside the plugin lock
+///
+/// Each plugin sits behind its own mutex so long-running plugin work (such
+/// as an export) only blocks that plugin, not the whole registry.
+struct PluginEntry {
+    descriptor: PluginDescriptor,
+    is_exporter: bool,
+    is_source: bool,
+    plugin: SharedPlugin,
+}
 
 /// Registry for managing plugins in the system
 pub struct PluginRegistry {
-    plugins: Arc<RwLock<HashMap<String, Box<dyn NovaPlugin>>>>,
+    plugins: Arc<RwLock<HashMap<String, PluginEntry>>>,
     context: PluginContext,
 }
 
@@ -31,10 +47,16 @@ impl PluginRegistry {
         // Store in registry
         let mut plugins = self.plugins.write().await;
         if plugins.contains_key(&descriptor.id) {
-            return Err(anyhow!("Plugin with ID '{}' is already registered", descriptor.id));
+            return Err(NovaError::PluginAlreadyRegistered(descriptor.id));
         }
         
-        plugins.insert(descriptor.id.clone(), plugin);
+        let entry = PluginEntry {
+            descriptor: descriptor.clone(),
+            is_exporter: plugin.as_exporter().is_some(),
+            is_source: plugin.as_source().is_some(),
+            plugin: Arc::new(Mutex::new(plugin)),
+        };
+        plugins.insert(descriptor.id.clone(), entry);
         
         tracing::info!("Registered plugin: {} v{}", descriptor.name, descriptor.version);
         Ok(())
@@ -42,44 +64,196 @@ impl PluginRegistry {
 
     /// Unregister a plugin by ID
     pub async fn unregister_plugin(&self, plugin_id: &str) -> PluginResult<()> {
-        let mut plugins = self.plugins.write().await;
+        let entry = self.plugins.write().await.remove(plugin_id);
         
-        if let Some(mut plugin) = plugins.remove(plugin_id) {
-            plugin.shutdown()?;
+        if let Some(entry) = entry {
+            entry.plugin.lock().await.shutdown()?;
             tracing::info!("Unregistered plugin: {}", plugin_id);
             Ok(())
         } else {
-            Err(anyhow!("Plugin '{}' not found", plugin_id))
+            Err(NovaError::PluginNotFound(plugin_id.to_string()))
         }
     }
 
     /// Get list of all registered plugin descriptors
     pub async fn list_plugins(&self) -> Vec<PluginDescriptor> {
         let plugins = self.plugins.read().await;
-        plugins.values().map(|p| p.descriptor().clone()).collect()
+        plugins.values().map(|e| e.descriptor.clone()).collect()
     }
 
     /// Get a specific plugin by ID
     pub async fn get_plugin(&self, plugin_id: &str) -> Option<PluginDescriptor> {
         let plugins = self.plugins.read().await;
-        plugins.get(plugin_id).map(|p| p.descriptor().clone())
+        plugins.get(plugin_id).map(|e| e.descriptor.clone())
     }
 
     /// Check health of all plugins
+    ///
+    /// Plugins that are busy (for example in the middle of an export) are
+    /// reported with a warning instead of waiting for them.
     pub async fn health_check_all(&self) -> HashMap<String, PluginHealth> {
         let plugins = self.plugins.read().await;
         let mut health_map = HashMap::new();
         
-        for (id, plugin) in plugins.iter() {
-            let health = plugin.health_check().unwrap_or(PluginHealth::Error {
-                message: "Health check failed".to_string(),
-            });
+        for (id, entry) in plugins.iter() {
+            let health = match entry.plugin.try_lock() {
+                Ok(plugin) => plugin.health_check().unwrap_or(PluginHealth::Error {
+                    message: "Health check failed".to_string(),
+                }),
+                Err(_) => PluginHealth::Warning {
+                    message: "Plugin is busy".to_string(),
+                },
+            };
             health_map.insert(id.clone(), health);
         }
         
         health_map
     }
 
+    /// Hand a finalized manifest to every registered exporter plugin
+    ///
+    /// Exporters run one at a time in plugin ID order on the blocking thread
+    /// pool, without holding the registry lock. Progress and outcomes are
+    /// published on the event bus, and each successful export is recorded in
+    /// `manifest.remote_locations`.
+    pub async fn run_exporters(
+        &self,
+        manifest: &mut ExportManifest,
+        chunks: Arc<dyn ChunkReader>,
+    ) -> HashMap<String, PluginResult<RemoteLocation>> {
+        let mut exporters: Vec<(String, SharedPlugin)> = {
+            let plugins = self.plugins.read().await;
+            plugins
+                .iter()
+                .filter(|(_, entry)| entry.is_exporter)
+                .map(|(id, entry)| (id.clone(), entry.plugin.clone()))
+                .collect()
+        };
+        exporters.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut results = HashMap::new();
+
+        for (id, plugin) in exporters {
+            let mut plugin = plugin.lock_owned().await;
+            let event_bus = self.context.event_bus.clone();
+            // Cloned per exporter so it sees locations recorded earlier in this run
+            let snapshot = Arc::new(manifest.clone());
+            let chunks = chunks.clone();
+            let exporter_id = id.clone();
+
+            let task = tokio::task::spawn_blocking(move || {
+                let Some(exporter) = plugin.as_exporter() else {
+                    return Err(NovaError::plugin(&exporter_id, "plugin is no longer an exporter"));
+                };
+                let mut report_progress = |mut progress: crate::ExportProgress| {
+                    progress.exporter_id = exporter_id.clone();
+                    event_bus.publish_now(NovaEvent::export_progress(exporter_id.clone(), &progress));
+                };
+                exporter.export(&snapshot, chunks.as_ref(), &mut report_progress)
+            });
+
+            let result = match task.await {
+                Ok(result) => result,
+                Err(e) => Err(NovaError::plugin(&id, format!("exporter task failed: {}", e))),
+            };
+
+            let event_bus = &self.context.event_bus;
+            let result = match result {
+                Ok(mut location) => {
+                    location.exporter_id = id.clone();
+                    tracing::info!("Exporter {} stored snapshot {} at {}", id, manifest.snapshot_id, location.uri);
+                    event_bus.publish_now(NovaEvent::export_completed(
+                        id.clone(),
+                        manifest.snapshot_id.clone(),
+                        &location,
+                    ));
+                    manifest.remote_locations.push(location.clone());
+                    Ok(location)
+                }
+                Err(e) => {
+                    // Plugin-reported errors carry the registry ID, like progress and locations
+                    let e = match e {
+                        NovaError::Plugin { message, .. } => NovaError::plugin(&id, message),
+                        e => e,
+                    };
+                    tracing::error!("Exporter {} failed for snapshot {}: {}", id, manifest.snapshot_id, e);
+                    event_bus.publish_now(NovaEvent::export_failed(
+                        id.clone(),
+                        manifest.snapshot_id.clone(),
+                        e.to_string(),
+                    ));
+                    Err(e)
+                }
+            };
+
+            results.insert(id, result);
+        }
+
+        results
+    }
+
+    /// Get descriptors of all plugins that provide a backup source
+    pub async fn list_sources(&self) -> Vec<PluginDescriptor> {
+        let plugins = self.plugins.read().await;
+        plugins
+            .values()
+            .filter(|entry| entry.is_source)
+            .map(|entry| entry.descriptor.clone())
+            .collect()
+    }
+
+    /// Stream the content of a source plugin into the host-provided writer
+    ///
+    /// The synchronous `read_source` runs on the blocking thread pool without
+    /// holding the registry lock, and the writer is handed back afterwards.
+    /// Progress reported by the plugin is published on the event bus.
+    pub async fn read_source<W>(&self, plugin_id: &str, mut writer: W) -> PluginResult<(SourceSummary, W)>
+    where
+        W: SourceWriter + 'static,
+    {
+        let plugin = self
+            .plugins
+            .read()
+            .await
+            .get(plugin_id)
+            .filter(|entry| entry.is_source)
+            .map(|entry| entry.plugin.clone());
+        let Some(plugin) = plugin else {
+            return Err(match self.get_plugin(plugin_id).await {
+                Some(_) => NovaError::plugin(plugin_id, "plugin does not provide a backup source"),
+                None => NovaError::PluginNotFound(plugin_id.to_string()),
+            });
+        };
+
+        let mut plugin = plugin.lock_owned().await;
+        let event_bus = self.context.event_bus.clone();
+        let source_id = plugin_id.to_string();
+
+        let task = tokio::task::spawn_blocking(move || {
+            let Some(source) = plugin.as_source() else {
+                return Err(NovaError::plugin(&source_id, "plugin does not provide a backup source"));
+            };
+            let mut report_progress = |mut progress: crate::SourceProgress| {
+                progress.source_id = source_id.clone();
+                event_bus.publish_now(NovaEvent::source_progress(source_id.clone(), &progress));
+            };
+            let summary = source.read_source(&mut writer, &mut report_progress)?;
+            Ok((summary, writer))
+        });
+
+        let (summary, writer) = task
+            .await
+            .map_err(|e| NovaError::plugin(plugin_id, format!("source task failed: {}", e)))??;
+
+        tracing::info!(
+            "Read source {}: {} files, {} bytes",
+            plugin_id,
+            summary.files,
+            summary.bytes
+        );
+        Ok((summary, writer))
+    }
+
     /// Get plugin count
     pub async fn plugin_count(&self) -> usize {
         let plugins = self.plugins.read().await;
@@ -88,14 +262,11 @@ impl PluginRegistry {
 
     /// Shutdown all plugins
     pub async fn shutdown_all(&self) -> PluginResult<()> {
-        let mut plugins = self.plugins.write().await;
-        let plugin_ids: Vec<String> = plugins.keys().cloned().collect();
+        let entries: Vec<(String, PluginEntry)> = self.plugins.write().await.drain().collect();
         
-        for plugin_id in plugin_ids {
-            if let Some(mut plugin) = plugins.remove(&plugin_id) {
-                if let Err(e) = plugin.shutdown() {
-                    tracing::error!("Failed to shutdown plugin {}: {}", plugin_id, e);
-                }
+        for (plugin_id, entry) in entries {
+            if let Err(e) = entry.plugin.lock().await.shutdown() {
+                tracing::error!("Failed to shutdown plugin {}: {}", plugin_id, e);
             }
         }
         
@@ -111,6 +282,8 @@ mod tests {
 
     struct TestPlugin {
         descriptor: PluginDescriptor,
+        exporter: Option<Box<dyn crate::ExporterPlugin>>,
+        source: Option<Box<dyn crate::SourcePlugin>>,
     }
 
     impl NovaPlugin for TestPlugin {
@@ -130,6 +303,20 @@ mod tests {
             Ok(PluginHealth::Healthy)
         }
 
+        fn as_exporter(&mut self) -> Option<&mut dyn crate::ExporterPlugin> {
+            match &mut self.exporter {
+                Some(exporter) => Some(exporter.as_mut()),
+                None => None,
+            }
+        }
+
+        fn as_source(&mut self) -> Option<&mut dyn crate::SourcePlugin> {
+            match &mut self.source {
+                Some(source) => Some(source.as_mut()),
+                None => None,
+            }
+        }
+
         fn as_any(&self) -> &dyn Any {
             self
         }
@@ -153,6 +340,22 @@ mod tests {
                 dependencies: HashMap::new(),
                 entry_point: None,
             },
+            exporter: None,
+            source: None,
+        }
+    }
+
+    fn create_test_exporter(id: &str, exporter: impl crate::ExporterPlugin + 'static) -> TestPlugin {
+        TestPlugin {
+            exporter: Some(Box::new(exporter)),
+            ..create_test_plugin(id)
+        }
+    }
+
+    fn create_test_source(id: &str, source: impl crate::SourcePlugin + 'static) -> TestPlugin {
+        TestPlugin {
+            source: Some(Box::new(source)),
+            ..create_test_plugin(id)
         }
     }
 
@@ -174,4 +377,339 @@ mod tests {
         assert_eq!(plugins.len(), 1);
         assert_eq!(plugins[0].id, "test1");
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_duplicate_and_missing_plugin_errors() {
+        let context = PluginContext {
+            config: Arc::new(RwLock::new(PluginConfig::new())),
+            event_bus: Arc::new(EventBus::new()),
+            capabilities: PluginCapabilities::default(),
+        };
+
+        let registry = PluginRegistry::new(context);
+        registry.register_plugin(Box::new(create_test_plugin("test1"))).await.unwrap();
+
+        let result = registry.register_plugin(Box::new(create_test_plugin("test1"))).await;
+        assert!(matches!(result, Err(NovaError::PluginAlreadyRegistered(id)) if id == "test1"));
+
+        let result = registry.unregister_plugin("missing").await;
+        assert!(matches!(result, Err(NovaError::PluginNotFound(id)) if id == "missing"));
+    }
+
+    struct MemoryExporter;
+
+    impl crate::ExporterPlugin for MemoryExporter {
+        fn export(
+            &mut self,
+            manifest: &ExportManifest,
+            chunks: &dyn ChunkReader,
+            progress: &mut dyn FnMut(crate::ExportProgress),
+        ) -> PluginResult<RemoteLocation> {
+            let mut bytes_uploaded = 0;
+            for (index, chunk_id) in manifest.files.iter().flat_map(|f| &f.chunks).enumerate() {
+                bytes_uploaded += chunks.read_chunk(chunk_id)?.len() as u64;
+                progress(crate::ExportProgress {
+                    // The registry must not trust plugin-supplied IDs
+                    exporter_id: "someone-else".to_string(),
+                    snapshot_id: manifest.snapshot_id.clone(),
+                    bytes_uploaded,
+                    bytes_total: manifest.total_bytes(),
+                    chunks_uploaded: index + 1,
+                    chunks_total: manifest.total_chunks(),
+                });
+            }
+
+            Ok(RemoteLocation {
+                exporter_id: "someone-else".to_string(),
+                uri: format!("memory://{}", manifest.snapshot_id),
+                exported_at: chrono::Utc::now(),
+                metadata: HashMap::from([(
+                    "prior_locations".to_string(),
+                    manifest.remote_locations.len().to_string(),
+                )]),
+            })
+        }
+    }
+
+    struct FailingExporter;
+
+    impl crate::ExporterPlugin for FailingExporter {
+        fn export(
+            &mut self,
+            _manifest: &ExportManifest,
+            _chunks: &dyn ChunkReader,
+            _progress: &mut dyn FnMut(crate::ExportProgress),
+        ) -> PluginResult<RemoteLocation> {
+            Err(NovaError::plugin("failing", "upload rejected"))
+        }
+    }
+
+    /// Exporter that waits at a barrier once when starting and once before finishing
+    struct BlockingExporter {
+        barrier: Arc<std::sync::Barrier>,
+    }
+
+    impl crate::ExporterPlugin for BlockingExporter {
+        fn export(
+            &mut self,
+            manifest: &ExportManifest,
+            _chunks: &dyn ChunkReader,
+            _progress: &mut dyn FnMut(crate::ExportProgress),
+        ) -> PluginResult<RemoteLocation> {
+            self.barrier.wait();
+            self.barrier.wait();
+            Ok(RemoteLocation {
+                exporter_id: "blocking".to_string(),
+                uri: format!("slow://{}", manifest.snapshot_id),
+                exported_at: chrono::Utc::now(),
+                metadata: HashMap::new(),
+            })
+        }
+    }
+
+    struct TestChunkReader;
+
+    impl ChunkReader for TestChunkReader {
+        fn read_chunk(&self, chunk_id: &str) -> PluginResult<Vec<u8>> {
+            Ok(chunk_id.as_bytes().to_vec())
+        }
+    }
+
+    fn create_test_registry() -> (PluginRegistry, Arc<EventBus>) {
+        let event_bus = Arc::new(EventBus::new());
+        let context = PluginContext {
+            config: Arc::new(RwLock::new(PluginConfig::new())),
+            event_bus: event_bus.clone(),
+            capabilities: PluginCapabilities::default(),
+        };
+        (PluginRegistry::new(context), event_bus)
+    }
+
+    fn create_test_manifest() -> ExportManifest {
+        ExportManifest {
+            snapshot_id: "snap1".to_string(),
+            label: None,
+            created_at: chrono::Utc::now(),
+            files: vec![crate::ExportFileEntry {
+                path: "docs/a.txt".to_string(),
+                size: 8,
+                chunks: vec!["abcd".to_string(), "efgh".to_string()],
+            }],
+            remote_locations: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_exporters_records_remote_location() {
+        let (registry, event_bus) = create_test_registry();
+        registry.register_plugin(Box::new(create_test_plugin("plain"))).await.unwrap();
+        registry
+            .register_plugin(Box::new(create_test_exporter("exporter", MemoryExporter)))
+            .await
+            .unwrap();
+
+        let mut subscription = event_bus.subscribe("test".to_string(), crate::EventFilter::default()).await;
+
+        let mut manifest = create_test_manifest();
+        let results = registry.run_exporters(&mut manifest, Arc::new(TestChunkReader)).await;
+
+        assert_eq!(results.len(), 1);
+        assert!(results["exporter"].is_ok());
+        assert_eq!(manifest.remote_locations.len(), 1);
+        assert_eq!(manifest.remote_locations[0].uri, "memory://snap1");
+        assert_eq!(manifest.remote_locations[0].exporter_id, "exporter");
+
+        for expected_chunks in [1, 2] {
+            let event = subscription.receiver.recv().await.unwrap();
+            assert_eq!(event.event_type, crate::EventType::ExportProgress);
+            assert_eq!(event.source, "exporter");
+            assert_eq!(event.data["exporter_id"], "exporter");
+            assert_eq!(event.data["chunks_uploaded"], expected_chunks);
+        }
+        let completed = subscription.receiver.recv().await.unwrap();
+        assert_eq!(completed.event_type, crate::EventType::ExportCompleted);
+        assert_eq!(completed.source, "exporter");
+    }
+
+    #[tokio::test]
+    async fn test_run_exporters_reports_failures() {
+        let (registry, event_bus) = create_test_registry();
+        registry
+            .register_plugin(Box::new(create_test_exporter("b-memory", MemoryExporter)))
+            .await
+            .unwrap();
+        registry
+            .register_plugin(Box::new(create_test_exporter("a-failing", FailingExporter)))
+            .await
+            .unwrap();
+
+        let mut subscription = event_bus.subscribe("test".to_string(), crate::EventFilter::default()).await;
+
+        let mut manifest = create_test_manifest();
+        let results = registry.run_exporters(&mut manifest, Arc::new(TestChunkReader)).await;
+
+        assert!(matches!(
+            &results["a-failing"],
+            Err(NovaError::Plugin { plugin_id, message }) if plugin_id == "a-failing" && message == "upload rejected"
+        ));
+        assert!(results["b-memory"].is_ok());
+        assert_eq!(manifest.remote_locations.len(), 1);
+        assert_eq!(manifest.remote_locations[0].exporter_id, "b-memory");
+
+        // Exporters run in ID order, so the failure is published first
+        let failed = subscription.receiver.recv().await.unwrap();
+        assert_eq!(failed.event_type, crate::EventType::ExportFailed);
+        assert_eq!(failed.source, "a-failing");
+        assert_eq!(failed.data["snapshot_id"], "snap1");
+        assert_eq!(failed.data["error"], "Plugin 'a-failing' failed: upload rejected");
+    }
+
+    #[tokio::test]
+    async fn test_later_exporters_see_earlier_locations() {
+        let (registry, _event_bus) = create_test_registry();
+        for id in ["first", "second"] {
+            registry
+                .register_plugin(Box::new(create_test_exporter(id, MemoryExporter)))
+                .await
+                .unwrap();
+        }
+
+        let mut manifest = create_test_manifest();
+        registry.run_exporters(&mut manifest, Arc::new(TestChunkReader)).await;
+
+        let prior: Vec<_> = manifest
+            .remote_locations
+            .iter()
+            .map(|l| (l.exporter_id.as_str(), l.metadata["prior_locations"].as_str()))
+            .collect();
+        assert_eq!(prior, vec![("first", "0"), ("second", "1")]);
+    }
+
+    #[tokio::test]
+    async fn test_registry_stays_available_while_exporting() {
+        let (registry, _event_bus) = create_test_registry();
+        let registry = Arc::new(registry);
+        let barrier = Arc::new(std::sync::Barrier::new(2));
+        registry
+            .register_plugin(Box::new(create_test_exporter(
+                "blocking",
+                BlockingExporter { barrier: barrier.clone() },
+            )))
+            .await
+            .unwrap();
+
+        let export = tokio::spawn({
+            let registry = registry.clone();
+            async move {
+                let mut manifest = create_test_manifest();
+                registry.run_exporters(&mut manifest, Arc::new(TestChunkReader)).await;
+                manifest
+            }
+        });
+
+        // Wait until the exporter is running
+        let wait = barrier.clone();
+        tokio::task::spawn_blocking(move || wait.wait()).await.unwrap();
+
+        let plugins = tokio::time::timeout(std::time::Duration::from_secs(5), registry.list_plugins())
+            .await
+            .expect("list_plugins blocked while an exporter was running");
+        assert_eq!(plugins.len(), 1);
+
+        let health = registry.health_check_all().await;
+        assert!(matches!(health["blocking"], PluginHealth::Warning { .. }));
+
+        // Let the exporter finish
+        let wait = barrier.clone();
+        tokio::task::spawn_blocking(move || wait.wait()).await.unwrap();
+
+        let manifest = export.await.unwrap();
+        assert_eq!(manifest.remote_locations[0].uri, "slow://snap1");
+    }
+
+    struct MailboxSource;
+
+    impl crate::SourcePlugin for MailboxSource {
+        fn read_source(
+            &mut self,
+            writer: &mut dyn SourceWriter,
+            progress: &mut dyn FnMut(crate::SourceProgress),
+        ) -> PluginResult<SourceSummary> {
+            let mut summary = SourceSummary::default();
+            for (path, content) in [("inbox/1.eml", "hello"), ("inbox/2.eml", "world!")] {
+                writer.begin_file(&crate::SourceEntry {
+                    path: path.to_string(),
+                    size: Some(content.len() as u64),
+                    modified: None,
+                })?;
+                writer.write(content.as_bytes())?;
+                writer.finish_file()?;
+
+                summary.files += 1;
+                summary.bytes += content.len() as u64;
+                progress(crate::SourceProgress {
+                    // The registry must not trust plugin-supplied IDs
+                    source_id: "someone-else".to_string(),
+                    files_done: summary.files,
+                    bytes_done: summary.bytes,
+                    files_total: Some(2),
+                    bytes_total: None,
+                });
+            }
+            Ok(summary)
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryWriter {
+        files: Vec<(String, Vec<u8>)>,
+    }
+
+    impl SourceWriter for MemoryWriter {
+        fn begin_file(&mut self, entry: &crate::SourceEntry) -> PluginResult<()> {
+            self.files.push((entry.path.clone(), Vec::new()));
+            Ok(())
+        }
+
+        fn write(&mut self, data: &[u8]) -> PluginResult<()> {
+            self.files.last_mut().unwrap().1.extend_from_slice(data);
+            Ok(())
+        }
+
+        fn finish_file(&mut self) -> PluginResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_source_streams_into_writer() {
+        let (registry, event_bus) = create_test_registry();
+        registry.register_plugin(Box::new(create_test_plugin("plain"))).await.unwrap();
+        registry
+            .register_plugin(Box::new(create_test_source("mailbox", MailboxSource)))
+            .await
+            .unwrap();
+
+        let sources = registry.list_sources().await;
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].id, "mailbox");
+
+        let mut subscription = event_bus.subscribe("test".to_string(), crate::EventFilter::default()).await;
+
+        let (summary, writer) = registry.read_source("mailbox", MemoryWriter::default()).await.unwrap();
+        assert_eq!(summary.files, 2);
+        assert_eq!(summary.bytes, 11);
+        assert_eq!(writer.files[1], ("inbox/2.eml".to_string(), b"world!".to_vec()));
+
+        let progress = subscription.receiver.recv().await.unwrap();
+        assert_eq!(progress.event_type, crate::EventType::SourceProgress);
+        assert_eq!(progress.source, "mailbox");
+        assert_eq!(progress.data["source_id"], "mailbox");
+
+        let result = registry.read_source("plain", MemoryWriter::default()).await;
+        assert!(matches!(result, Err(NovaError::Plugin { .. })));
+
+        let result = registry.read_source("missing", MemoryWriter::default()).await;
+        assert!(matches!(result, Err(NovaError::PluginNotFound(_))));
+    }
+}
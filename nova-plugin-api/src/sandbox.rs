@@ -78,6 +78,12 @@ pub struct WasmPluginExecutor {
     _placeholder: (),
 }
 
+impl Default for WasmPluginExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl WasmPluginExecutor {
     pub fn new() -> Self {
         Self { _placeholder: () }
@@ -0,0 +1,57 @@
+use crate::PluginResult;
+use serde::{Deserialize, Serialize};
+
+/// File produced by a source plugin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceEntry {
+    /// Path of the file relative to the source root
+    pub path: String,
+    pub size: Option<u64>,
+    pub modified: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Progress reported by a source plugin while streaming content
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceProgress {
+    pub source_id: String,
+    pub files_done: usize,
+    pub bytes_done: u64,
+    pub files_total: Option<usize>,
+    pub bytes_total: Option<u64>,
+}
+
+/// Totals returned once a source has been fully read
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SourceSummary {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// Host-provided sink feeding source content into the chunking pipeline
+///
+/// Content is streamed one file at a time: `begin_file`, any number of
+/// `write` calls, then `finish_file`.
+pub trait SourceWriter: Send {
+    /// Start a new file
+    fn begin_file(&mut self, entry: &SourceEntry) -> PluginResult<()>;
+
+    /// Append content to the current file
+    fn write(&mut self, data: &[u8]) -> PluginResult<()>;
+
+    /// Complete the current file
+    fn finish_file(&mut self) -> PluginResult<()>;
+}
+
+/// Contract for plugins that provide new backup sources
+///
+/// Examples are an IMAP mailbox, a Google Takeout folder or a Nextcloud
+/// account. The host drives the source when a backup runs and the plugin
+/// streams each file through the [`SourceWriter`].
+pub trait SourcePlugin: Send + Sync {
+    /// Stream every file of the source into the writer
+    fn read_source(
+        &mut self,
+        writer: &mut dyn SourceWriter,
+        progress: &mut dyn FnMut(SourceProgress),
+    ) -> PluginResult<SourceSummary>;
+}
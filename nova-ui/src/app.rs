@@ -1,10 +1,14 @@
+use crate::i18n::Locale;
 use eframe::egui;
 use nova_plugin_api::PluginRegistry;
 use std::sync::Arc;
 
 /// Main application UI
 pub struct NovaApp {
+    // Not read until the dashboard queries the registry
+    #[allow(dead_code)]
     plugin_registry: Arc<PluginRegistry>,
+    locale: Locale,
     current_tab: AppTab,
     extensions_ui: crate::extensions::ExtensionsUI,
 }
@@ -18,27 +22,36 @@ enum AppTab {
 }
 
 impl NovaApp {
+    /// Create the application using the locale detected from the environment
     pub fn new(plugin_registry: Arc<PluginRegistry>) -> Self {
+        Self::with_locale(plugin_registry, Locale::detect())
+    }
+
+    /// Create the application with an explicit display language
+    pub fn with_locale(plugin_registry: Arc<PluginRegistry>, locale: Locale) -> Self {
         Self {
             plugin_registry: plugin_registry.clone(),
+            locale,
             current_tab: AppTab::Dashboard,
-            extensions_ui: crate::extensions::ExtensionsUI::new(plugin_registry),
+            extensions_ui: crate::extensions::ExtensionsUI::new(plugin_registry, locale),
         }
     }
 }
 
 impl eframe::App for NovaApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        let locale = self.locale;
+
         // Top menu bar
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
-                ui.menu_button("File", |ui| {
-                    if ui.button("Exit").clicked() {
+                ui.menu_button(locale.tr("menu.file"), |ui| {
+                    if ui.button(locale.tr("menu.exit")).clicked() {
                         ctx.send_viewport_cmd(egui::ViewportCommand::Close);
                     }
                 });
-                ui.menu_button("Help", |ui| {
-                    if ui.button("About").clicked() {
+                ui.menu_button(locale.tr("menu.help"), |ui| {
+                    if ui.button(locale.tr("menu.about")).clicked() {
                         // Show about dialog
                     }
                 });
@@ -50,16 +63,16 @@ impl eframe::App for NovaApp {
             ui.heading("NovaPcSuite");
             ui.separator();
 
-            if ui.selectable_label(self.current_tab == AppTab::Dashboard, "📊 Dashboard").clicked() {
+            if ui.selectable_label(self.current_tab == AppTab::Dashboard, locale.tr("nav.dashboard")).clicked() {
                 self.current_tab = AppTab::Dashboard;
             }
-            if ui.selectable_label(self.current_tab == AppTab::Backup, "💾 Backup").clicked() {
+            if ui.selectable_label(self.current_tab == AppTab::Backup, locale.tr("nav.backup")).clicked() {
                 self.current_tab = AppTab::Backup;
             }
-            if ui.selectable_label(self.current_tab == AppTab::Extensions, "🧩 Extensions").clicked() {
+            if ui.selectable_label(self.current_tab == AppTab::Extensions, locale.tr("nav.extensions")).clicked() {
                 self.current_tab = AppTab::Extensions;
             }
-            if ui.selectable_label(self.current_tab == AppTab::Settings, "⚙️ Settings").clicked() {
+            if ui.selectable_label(self.current_tab == AppTab::Settings, locale.tr("nav.settings")).clicked() {
                 self.current_tab = AppTab::Settings;
             }
         });
@@ -68,26 +81,26 @@ impl eframe::App for NovaApp {
         egui::CentralPanel::default().show(ctx, |ui| {
             match self.current_tab {
                 AppTab::Dashboard => {
-                    ui.heading("Dashboard");
-                    ui.label("Welcome to NovaPcSuite!");
+                    ui.heading(locale.tr("dashboard.title"));
+                    ui.label(locale.tr("dashboard.welcome"));
                     ui.separator();
                     
                     // Show some basic stats
                     ui.horizontal(|ui| {
-                        ui.label("Active Plugins:");
-                        ui.label("Loading...");
+                        ui.label(locale.tr("dashboard.active_plugins"));
+                        ui.label(locale.tr("dashboard.loading"));
                     });
                 }
                 AppTab::Backup => {
-                    ui.heading("Backup Management");
-                    ui.label("Backup functionality will be implemented here.");
+                    ui.heading(locale.tr("backup.title"));
+                    ui.label(locale.tr("backup.placeholder"));
                 }
                 AppTab::Extensions => {
                     self.extensions_ui.update(ui, ctx);
                 }
                 AppTab::Settings => {
-                    ui.heading("Settings");
-                    ui.label("Application settings will be implemented here.");
+                    ui.heading(locale.tr("settings.title"));
+                    ui.label(locale.tr("settings.placeholder"));
                 }
             }
         });
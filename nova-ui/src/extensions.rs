@@ -1,3 +1,4 @@
+use crate::i18n::Locale;
 use eframe::egui;
 use nova_plugin_api::{PluginRegistry, PluginDescriptor, PluginHealth};
 use std::sync::Arc;
@@ -5,7 +6,10 @@ use std::collections::HashMap;
 
 /// Extensions/Plugins management UI
 pub struct ExtensionsUI {
+    // Not read until refresh queries the registry
+    #[allow(dead_code)]
     plugin_registry: Arc<PluginRegistry>,
+    locale: Locale,
     plugins: Vec<PluginDescriptor>,
     plugin_health: HashMap<String, PluginHealth>,
     selected_plugin: Option<String>,
@@ -13,9 +17,10 @@ pub struct ExtensionsUI {
 }
 
 impl ExtensionsUI {
-    pub fn new(plugin_registry: Arc<PluginRegistry>) -> Self {
+    pub fn new(plugin_registry: Arc<PluginRegistry>, locale: Locale) -> Self {
         Self {
             plugin_registry,
+            locale,
             plugins: vec![],
             plugin_health: HashMap::new(),
             selected_plugin: None,
@@ -24,16 +29,18 @@ impl ExtensionsUI {
     }
 
     pub fn update(&mut self, ui: &mut egui::Ui, _ctx: &egui::Context) {
-        ui.heading("Extensions");
+        let locale = self.locale;
+
+        ui.heading(locale.tr("extensions.title"));
         ui.separator();
 
         // Refresh button
         ui.horizontal(|ui| {
-            if ui.button("🔄 Refresh").clicked() {
+            if ui.button(locale.tr("extensions.refresh")).clicked() {
                 self.refresh_requested = true;
             }
             ui.separator();
-            ui.label(format!("Total plugins: {}", self.plugins.len()));
+            ui.label(format!("{} {}", locale.tr("extensions.total_plugins"), self.plugins.len()));
         });
 
         ui.separator();
@@ -60,7 +67,7 @@ impl ExtensionsUI {
                 [ui.available_width() * 0.4, ui.available_height()].into(),
                 egui::Layout::top_down(egui::Align::LEFT),
                 |ui| {
-                    ui.heading("Installed Plugins");
+                    ui.heading(locale.tr("extensions.installed"));
                     ui.separator();
 
                     egui::ScrollArea::vertical().show(ui, |ui| {
@@ -96,9 +103,9 @@ impl ExtensionsUI {
                             self.show_plugin_details(ui, &plugin_clone);
                         }
                     } else {
-                        ui.heading("Plugin Details");
+                        ui.heading(locale.tr("extensions.details"));
                         ui.separator();
-                        ui.label("Select a plugin to view details");
+                        ui.label(locale.tr("extensions.select_plugin"));
                     }
                 },
             );
@@ -106,36 +113,38 @@ impl ExtensionsUI {
     }
 
     fn show_plugin_details(&mut self, ui: &mut egui::Ui, plugin: &PluginDescriptor) {
+        let locale = self.locale;
+
         ui.heading(&plugin.name);
         ui.separator();
 
         // Basic info
         ui.horizontal(|ui| {
-            ui.label("Version:");
+            ui.label(locale.tr("extensions.version"));
             ui.code(plugin.version.to_string());
         });
 
         ui.horizontal(|ui| {
-            ui.label("ID:");
+            ui.label(locale.tr("extensions.id"));
             ui.code(&plugin.id);
         });
 
         ui.horizontal(|ui| {
-            ui.label("API Version:");
+            ui.label(locale.tr("extensions.api_version"));
             ui.code(plugin.api_version.to_string());
         });
 
         ui.add_space(10.0);
 
         // Description
-        ui.label("Description:");
+        ui.label(locale.tr("extensions.description"));
         ui.label(&plugin.description);
 
         ui.add_space(10.0);
 
         // Authors
         if !plugin.authors.is_empty() {
-            ui.label("Authors:");
+            ui.label(locale.tr("extensions.authors"));
             for author in &plugin.authors {
                 ui.label(format!("  • {}", author));
             }
@@ -144,7 +153,7 @@ impl ExtensionsUI {
 
         // Categories
         if !plugin.categories.is_empty() {
-            ui.label("Categories:");
+            ui.label(locale.tr("extensions.categories"));
             ui.horizontal_wrapped(|ui| {
                 for category in &plugin.categories {
                     ui.label(egui::RichText::new(format!("{:?}", category)).weak());
@@ -155,43 +164,43 @@ impl ExtensionsUI {
 
         // Health status
         if let Some(health) = self.plugin_health.get(&plugin.id) {
-            ui.label("Status:");
+            ui.label(locale.tr("extensions.status"));
             match health {
                 PluginHealth::Healthy => {
-                    ui.colored_label(egui::Color32::GREEN, "✅ Healthy");
+                    ui.colored_label(egui::Color32::GREEN, locale.tr("extensions.healthy"));
                 }
                 PluginHealth::Warning { message } => {
-                    ui.colored_label(egui::Color32::YELLOW, format!("⚠️ Warning: {}", message));
+                    ui.colored_label(egui::Color32::YELLOW, format!("{} {}", locale.tr("extensions.warning"), message));
                 }
                 PluginHealth::Error { message } => {
-                    ui.colored_label(egui::Color32::RED, format!("❌ Error: {}", message));
+                    ui.colored_label(egui::Color32::RED, format!("{} {}", locale.tr("extensions.error"), message));
                 }
             }
             ui.add_space(10.0);
         }
 
         // Capabilities
-        ui.label("Capabilities:");
+        ui.label(locale.tr("extensions.capabilities"));
         ui.indent("capabilities", |ui| {
-            capability_checkbox(ui, "File System Access", plugin.capabilities.file_system_access);
-            capability_checkbox(ui, "Network Access", plugin.capabilities.network_access);
-            capability_checkbox(ui, "System Info Access", plugin.capabilities.system_info_access);
-            capability_checkbox(ui, "Backup Events", plugin.capabilities.backup_events);
-            capability_checkbox(ui, "UI Panels", plugin.capabilities.ui_panels);
-            capability_checkbox(ui, "Config UI", plugin.capabilities.config_ui);
+            capability_checkbox(ui, locale.tr("capability.file_system"), plugin.capabilities.file_system_access);
+            capability_checkbox(ui, locale.tr("capability.network"), plugin.capabilities.network_access);
+            capability_checkbox(ui, locale.tr("capability.system_info"), plugin.capabilities.system_info_access);
+            capability_checkbox(ui, locale.tr("capability.backup_events"), plugin.capabilities.backup_events);
+            capability_checkbox(ui, locale.tr("capability.ui_panels"), plugin.capabilities.ui_panels);
+            capability_checkbox(ui, locale.tr("capability.config_ui"), plugin.capabilities.config_ui);
         });
 
         ui.add_space(10.0);
 
         // Action buttons
         ui.horizontal(|ui| {
-            if ui.button("Configure").clicked() {
+            if ui.button(locale.tr("extensions.configure")).clicked() {
                 // TODO: Open plugin configuration dialog
             }
-            if ui.button("Disable").clicked() {
+            if ui.button(locale.tr("extensions.disable")).clicked() {
                 // TODO: Disable plugin
             }
-            if ui.button("Remove").clicked() {
+            if ui.button(locale.tr("extensions.remove")).clicked() {
                 // TODO: Remove plugin
             }
         });
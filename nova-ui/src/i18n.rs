@@ -0,0 +1,189 @@
+/// Languages the user interface can be displayed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    English,
+    Italian,
+}
+
+impl Locale {
+    /// Parse a language tag such as `it`, `it_IT.UTF-8` or `en-US`
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match language.as_str() {
+            "en" => Some(Locale::English),
+            "it" => Some(Locale::Italian),
+            _ => None,
+        }
+    }
+
+    /// Detect the locale from `NOVA_LANG`, then the standard POSIX variables
+    ///
+    /// Unset or unsupported values fall through to the next variable.
+    pub fn detect() -> Self {
+        Self::detect_with(|var| std::env::var(var).ok())
+    }
+
+    fn detect_with(get_var: impl Fn(&str) -> Option<String>) -> Self {
+        ["NOVA_LANG", "LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| get_var(var))
+            .find_map(|value| Self::from_tag(&value))
+            .unwrap_or_default()
+    }
+
+    /// Translate a message key, falling back to English and then to the key itself
+    pub fn tr(self, key: &'static str) -> &'static str {
+        lookup(self.catalog(), key)
+            .or_else(|| lookup(EN, key))
+            .unwrap_or(key)
+    }
+
+    fn catalog(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::English => EN,
+            Locale::Italian => IT,
+        }
+    }
+}
+
+fn lookup(catalog: &'static [(&'static str, &'static str)], key: &str) -> Option<&'static str> {
+    catalog
+        .iter()
+        .find(|(entry_key, _)| *entry_key == key)
+        .map(|(_, message)| *message)
+}
+
+const EN: &[(&str, &str)] = &[
+    ("menu.file", "File"),
+    ("menu.exit", "Exit"),
+    ("menu.help", "Help"),
+    ("menu.about", "About"),
+    ("nav.dashboard", "📊 Dashboard"),
+    ("nav.backup", "💾 Backup"),
+    ("nav.extensions", "🧩 Extensions"),
+    ("nav.settings", "⚙️ Settings"),
+    ("dashboard.title", "Dashboard"),
+    ("dashboard.welcome", "Welcome to NovaPcSuite!"),
+    ("dashboard.active_plugins", "Active Plugins:"),
+    ("dashboard.loading", "Loading..."),
+    ("backup.title", "Backup Management"),
+    ("backup.placeholder", "Backup functionality will be implemented here."),
+    ("settings.title", "Settings"),
+    ("settings.placeholder", "Application settings will be implemented here."),
+    ("extensions.title", "Extensions"),
+    ("extensions.refresh", "🔄 Refresh"),
+    ("extensions.total_plugins", "Total plugins:"),
+    ("extensions.installed", "Installed Plugins"),
+    ("extensions.details", "Plugin Details"),
+    ("extensions.select_plugin", "Select a plugin to view details"),
+    ("extensions.version", "Version:"),
+    ("extensions.id", "ID:"),
+    ("extensions.api_version", "API Version:"),
+    ("extensions.description", "Description:"),
+    ("extensions.authors", "Authors:"),
+    ("extensions.categories", "Categories:"),
+    ("extensions.status", "Status:"),
+    ("extensions.healthy", "✅ Healthy"),
+    ("extensions.warning", "⚠️ Warning:"),
+    ("extensions.error", "❌ Error:"),
+    ("extensions.capabilities", "Capabilities:"),
+    ("capability.file_system", "File System Access"),
+    ("capability.network", "Network Access"),
+    ("capability.system_info", "System Info Access"),
+    ("capability.backup_events", "Backup Events"),
+    ("capability.ui_panels", "UI Panels"),
+    ("capability.config_ui", "Config UI"),
+    ("extensions.configure", "Configure"),
+    ("extensions.disable", "Disable"),
+    ("extensions.remove", "Remove"),
+];
+
+const IT: &[(&str, &str)] = &[
+    ("menu.file", "File"),
+    ("menu.exit", "Esci"),
+    ("menu.help", "Aiuto"),
+    ("menu.about", "Informazioni"),
+    ("nav.dashboard", "📊 Riepilogo"),
+    ("nav.backup", "💾 Backup"),
+    ("nav.extensions", "🧩 Estensioni"),
+    ("nav.settings", "⚙️ Impostazioni"),
+    ("dashboard.title", "Riepilogo"),
+    ("dashboard.welcome", "Benvenuto in NovaPcSuite!"),
+    ("dashboard.active_plugins", "Plugin attivi:"),
+    ("dashboard.loading", "Caricamento..."),
+    ("backup.title", "Gestione backup"),
+    ("backup.placeholder", "Le funzionalità di backup saranno disponibili qui."),
+    ("settings.title", "Impostazioni"),
+    ("settings.placeholder", "Le impostazioni dell'applicazione saranno disponibili qui."),
+    ("extensions.title", "Estensioni"),
+    ("extensions.refresh", "🔄 Aggiorna"),
+    ("extensions.total_plugins", "Plugin totali:"),
+    ("extensions.installed", "Plugin installati"),
+    ("extensions.details", "Dettagli plugin"),
+    ("extensions.select_plugin", "Seleziona un plugin per vederne i dettagli"),
+    ("extensions.version", "Versione:"),
+    ("extensions.id", "ID:"),
+    ("extensions.api_version", "Versione API:"),
+    ("extensions.description", "Descrizione:"),
+    ("extensions.authors", "Autori:"),
+    ("extensions.categories", "Categorie:"),
+    ("extensions.status", "Stato:"),
+    ("extensions.healthy", "✅ Funzionante"),
+    ("extensions.warning", "⚠️ Avviso:"),
+    ("extensions.error", "❌ Errore:"),
+    ("extensions.capabilities", "Permessi:"),
+    ("capability.file_system", "Accesso al file system"),
+    ("capability.network", "Accesso alla rete"),
+    ("capability.system_info", "Informazioni di sistema"),
+    ("capability.backup_events", "Eventi di backup"),
+    ("capability.ui_panels", "Pannelli UI"),
+    ("capability.config_ui", "Interfaccia di configurazione"),
+    ("extensions.configure", "Configura"),
+    ("extensions.disable", "Disattiva"),
+    ("extensions.remove", "Rimuovi"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_from_tag() {
+        assert_eq!(Locale::from_tag("it"), Some(Locale::Italian));
+        assert_eq!(Locale::from_tag("it_IT.UTF-8"), Some(Locale::Italian));
+        assert_eq!(Locale::from_tag("en-US"), Some(Locale::English));
+        assert_eq!(Locale::from_tag("C"), None);
+    }
+
+    #[test]
+    fn test_detect_skips_unsupported_values() {
+        let env = |var: &str| match var {
+            "NOVA_LANG" => Some("fr".to_string()),
+            "LANG" => Some("it_IT.UTF-8".to_string()),
+            _ => None,
+        };
+        assert_eq!(Locale::detect_with(env), Locale::Italian);
+        assert_eq!(Locale::detect_with(|_| None), Locale::English);
+    }
+
+    #[test]
+    fn test_translation_and_fallback() {
+        assert_eq!(Locale::Italian.tr("menu.exit"), "Esci");
+        assert_eq!(Locale::English.tr("menu.exit"), "Exit");
+        assert_eq!(Locale::Italian.tr("missing.key"), "missing.key");
+    }
+
+    #[test]
+    fn test_catalogs_cover_same_keys() {
+        for (key, _) in EN {
+            assert!(lookup(IT, key).is_some(), "missing Italian translation for {}", key);
+        }
+        assert_eq!(EN.len(), IT.len());
+    }
+}
@@ -1,5 +1,7 @@
 pub mod app;
 pub mod extensions;
+pub mod i18n;
 
 pub use app::*;
-pub use extensions::*;
\ No newline at end of file
+pub use extensions::*;
+pub use i18n::Locale;
\ No newline at end of file
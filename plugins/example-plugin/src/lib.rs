@@ -22,6 +22,8 @@ impl ExamplePlugin {
         })
     }
 
+    // Not called until the host forwards events to plugins
+    #[allow(dead_code)]
     async fn handle_backup_event(&self, event: &NovaEvent) -> PluginResult<()> {
         match event.event_type {
             EventType::BackupStarted => {